@@ -1,3 +1,4 @@
+use halo2_proofs::circuit::Chip;
 use halo2_proofs::plonk::Expression;
 use halo2_proofs::poly::Rotation;
 use halo2_proofs::{arithmetic::FieldExt, circuit::*, plonk::*};
@@ -7,119 +8,552 @@ use std::marker::PhantomData;
 // example 3^4 = 81 [base = 3, exp = 4, result = 81]
 // result = running product starting with the base
 // exp = decrements the initial exp by 1 each step
-// base = duplicates the base so I can have access to it while constraining
 // instance = public inputs [3, 4, 81]
-
-// result | exp | base | selector | instance
-//   3    |  4  |  3   |    1     |    3
-//   9    |  3  |  3   |    1     |    4
-//  27    |  2  |  3   |    1     |    81
-//  81    |  1  |  3   |    0     |    _
-
-// Constraints
-// result_i * base_i = result_{i+1}
-// exp_i - 1 = exp_{i+1}
-// base_i = base_{i+1}
-
-// Discussion
-// This can be done with only two advice columns
-// the first entry of the result column already contains the base so I shouldn't need the base column
+//
+// Both linear-mode paths below use only two advice columns (`result`, `exponent`); neither
+// carries a dedicated per-row base column.
+//
+//  - constant base (`exp_linear_const_base`): the base lives in a `Fixed` column queried
+//    straight from the gate, so it costs nothing per row:
 // result | exp | selector | instance
 //   3    |  4  |    1     |    3
 //   9    |  3  |    1     |    4
 //  27    |  2  |    1     |    81
 //  81    |  1  |    0     |    _
-// updated constraints
-// result_i * result[0] = result_{i+1}
+// constraints
+// result_i * base = result_{i+1}   (base read from base_fixed, not an advice column)
 // exp_i - 1 = exp_{i + 1}
-// but struggled to reference result[0] from the gate definition
+//
+//  - witness base (`exp_linear`): there's no fixed column to read a witnessed base from, so it
+//    is copy-constrained into the `result` column itself, one row ahead of where the gate reads
+//    the running result/exponent it's paired with. Every step therefore spans two rows instead
+//    of one: a "base row" holding a fresh copy-constrained copy of the base, followed by the row
+//    holding the new running result/exponent. The gate reads the base via `Rotation::cur()` on
+//    the row it's enabled at, and the running result/exponent one row behind (`Rotation::prev()`)
+//    and one row ahead (`Rotation::next()`):
+// result | exp | selector | instance
+//   3    |  4  |    0     |    3
+//   3    |  _  |    1     |    4      (base row: Rotation::cur() = base, ::prev() = row above, ::next() = row below)
+//   9    |  3  |    0     |    81
+//   3    |  _  |    1     |    _
+//  27    |  2  |    0     |    _
+//   3    |  _  |    1     |    _
+//  81    |  1  |    0     |    _
+// constraints (checked at each enabled base row)
+// result_{i-1} * base = result_{i+1}
+// exp_{i-1} - 1 = exp_{i+1}
 
-#[derive(Clone)]
+// Binary (square-and-multiply) mode lays out the exponent as its little-endian bit
+// decomposition `e_0..e_{n-1}` and computes `base^exp` in O(log exp) rows instead of O(exp):
+//
+// bit | acc_bit | acc | sq | prod | selector
+//  e0 |  e_{n-1}|  0  | base |  1  |    1
+//  e1 |  e_{n-2}| e_{n-1} | base^2 | 1*(e0?base:1) | 1
+//  .. |   ..    |  .. | .. | .. | ..
+//
+// `acc` reconstructs the exponent value by consuming bits MSB-first (via the `acc_bit` mirror
+// of `bit`) and is constrained equal to the exponent cell once all rows are assigned. `sq`
+// doubles (squares) every row starting from `base`, and `prod` conditionally multiplies the
+// running square into the result whenever the corresponding bit is set, ending at `base^exp`.
+//
+// Constraints (per row)
+// bit * (bit - 1) = 0
+// acc_next = 2 * acc + acc_bit
+// sq_next = sq * sq
+// prod_next = prod * (1 + bit * (sq - 1))
+
+// Range-checking the exponent
+//
+// Binary mode decomposes the exponent into `num_bits` little-endian bits and reconstructs it
+// via `acc`, but that reconstruction is only a faithful (unique) representation of the exponent
+// if the exponent itself is known to fit in `num_bits` bits; otherwise a malicious prover could
+// witness a value that wraps around the field and still satisfy the `acc` constraint. A lookup
+// against a fixed table of `0..2^t` closes that gap: the exponent cell is looked up in the table
+// before it is decomposed, so the circuit no longer has to trust the public input's range.
+
+/// Selects which strategy `exp` runs: the original O(exp) linear decrement, or the
+/// O(log exp) square-and-multiply decomposition.
+#[derive(Clone, Copy, Debug)]
+enum ExpMode {
+    Linear,
+    Binary { num_bits: usize },
+}
+
+impl Default for ExpMode {
+    fn default() -> Self {
+        ExpMode::Linear
+    }
+}
+
+/// Bit-width `t` of the fixed exponent range-check table shared by all circuits in this crate;
+/// must be `>= num_bits` for any `ExpMode::Binary { num_bits }` the circuit uses.
+const EXPONENT_RANGE_BITS: usize = 4;
+
+// Instruction set for the chip, mirroring the load_private / load_constant / expose_public
+// pattern so the chip can be composed into bigger circuits instead of only proving a single
+// hard-wired exponentiation.
+trait ExpInstructions<F: FieldExt>: Chip<F> {
+    /// Variable representing a value loaded into the chip (base, exponent or result).
+    type Num;
+
+    /// Loads a private value as a witness.
+    fn load_private(&self, layouter: impl Layouter<F>, value: Option<F>) -> Result<Self::Num, Error>;
+
+    /// Loads a compile-time constant, without consuming an instance row.
+    fn load_constant(&self, layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error>;
+
+    /// Computes `base ^ exponent`.
+    fn exp(
+        &self,
+        layouter: impl Layouter<F>,
+        base: Self::Num,
+        exponent: Self::Num,
+    ) -> Result<Self::Num, Error>;
+
+    /// Constrains `num` to equal the instance column's value at `row`.
+    fn expose_public(&self, layouter: impl Layouter<F>, num: Self::Num, row: usize) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug)]
 struct ExpConfig {
-    pub advice: [Column<Advice>; 3],
-    pub selector: Selector,
-    pub instance: Column<Instance>,
+    // linear mode (witness base): running result, decrementing exponent; the base is
+    // copy-constrained into `advice[0]` one row ahead of the step it's paired with (see
+    // `exp_linear_inner`) rather than carried in a dedicated column
+    advice: [Column<Advice>; 2],
+    linear_selector: Selector,
+    // linear mode (constant base): running result, decrementing exponent; the base is read
+    // straight from `base_fixed` instead of carried down an advice column
+    base_fixed: Column<Fixed>,
+    const_base_selector: Selector,
+    // binary mode: bit decomposition, mirrored bit for reconstruction, accumulator, running
+    // square and running product
+    bit: Column<Advice>,
+    acc_bit: Column<Advice>,
+    acc: Column<Advice>,
+    sq: Column<Advice>,
+    prod: Column<Advice>,
+    binary_selector: Selector,
+    instance: Column<Instance>,
+    constant: Column<Fixed>,
+    // range check: the exponent must be a member of `0..2^range_bits` before binary mode
+    // decomposes it into bits
+    range_table: TableColumn,
+    range_selector: Selector,
+    range_bits: usize,
 }
 
 struct ExpChip<F: FieldExt> {
     config: ExpConfig,
+    mode: ExpMode,
     _marker: PhantomData<F>,
 }
 
+impl<F: FieldExt> Chip<F> for ExpChip<F> {
+    type Config = ExpConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
 impl<F: FieldExt> ExpChip<F> {
-    fn construct(config: ExpConfig) -> Self {
+    fn construct(config: ExpConfig, mode: ExpMode) -> Self {
         Self {
             config,
+            mode,
             _marker: PhantomData,
         }
     }
 
-    fn configure(meta: &mut ConstraintSystem<F>) -> ExpConfig {
+    /// `range_bits` (`t`) fixes the bit-width of the exponent range-check table; binary-mode
+    /// callers must pass `num_bits <= range_bits` to `exp`, or the lookup no longer covers the
+    /// full space the decomposition can represent.
+    fn configure(meta: &mut ConstraintSystem<F>, range_bits: usize) -> ExpConfig {
         let result_column = meta.advice_column();
         let exponent_column = meta.advice_column();
-        let base_column = meta.advice_column();
-        let sel = meta.selector();
+        let linear_sel = meta.selector();
+
+        let base_fixed = meta.fixed_column();
+        let const_base_sel = meta.selector();
+
+        let bit = meta.advice_column();
+        let acc_bit = meta.advice_column();
+        let acc = meta.advice_column();
+        let sq = meta.advice_column();
+        let prod = meta.advice_column();
+        let binary_sel = meta.selector();
+
         let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        let range_table = meta.lookup_table_column();
+        let range_selector = meta.complex_selector();
 
         meta.enable_equality(result_column);
         meta.enable_equality(exponent_column);
-        meta.enable_equality(base_column);
+        meta.enable_equality(bit);
+        meta.enable_equality(acc_bit);
+        meta.enable_equality(acc);
+        meta.enable_equality(sq);
+        meta.enable_equality(prod);
         meta.enable_equality(instance);
+        meta.enable_constant(constant);
+
+        meta.create_gate("exp_linear", |meta| {
+            let s = meta.query_selector(linear_sel);
+            // the gate is enabled on the "base row" between the running result/exponent it
+            // reads (one row behind) and the ones it produces (one row ahead), so the witnessed
+            // base never needs a dedicated column: it's copy-constrained straight into this row
+            // of `result_column` instead.
+            let prev_running_result = meta.query_advice(result_column, Rotation::prev());
+            let base = meta.query_advice(result_column, Rotation::cur());
+            let current_result = meta.query_advice(result_column, Rotation::next());
+            let prev_exp = meta.query_advice(exponent_column, Rotation::prev());
+            let curr_exp = meta.query_advice(exponent_column, Rotation::next());
+
+            vec![
+                s.clone() * ((prev_running_result * base) - current_result),
+                s * ((prev_exp - curr_exp) - Expression::Constant(F::one())),
+            ]
+        });
 
-        meta.create_gate("exp", |meta| {
-            let s = meta.query_selector(sel);
+        meta.create_gate("exp_linear_const_base", |meta| {
+            let s = meta.query_selector(const_base_sel);
             let prev_running_result = meta.query_advice(result_column, Rotation::cur());
             let current_result = meta.query_advice(result_column, Rotation::next());
             let prev_exp = meta.query_advice(exponent_column, Rotation::cur());
             let curr_exp = meta.query_advice(exponent_column, Rotation::next());
-            let prev_base = meta.query_advice(base_column, Rotation::cur());
-            let curr_base = meta.query_advice(base_column, Rotation::next());
+            let base = meta.query_fixed(base_fixed, Rotation::cur());
+
+            vec![
+                s.clone() * ((prev_running_result * base) - current_result),
+                s * ((prev_exp - curr_exp) - Expression::Constant(F::one())),
+            ]
+        });
+
+        meta.create_gate("exp_binary", |meta| {
+            let s = meta.query_selector(binary_sel);
+            let bit = meta.query_advice(bit, Rotation::cur());
+            let acc_bit = meta.query_advice(acc_bit, Rotation::cur());
+            let cur_acc = meta.query_advice(acc, Rotation::cur());
+            let next_acc = meta.query_advice(acc, Rotation::next());
+            let cur_sq = meta.query_advice(sq, Rotation::cur());
+            let next_sq = meta.query_advice(sq, Rotation::next());
+            let cur_prod = meta.query_advice(prod, Rotation::cur());
+            let next_prod = meta.query_advice(prod, Rotation::next());
 
             vec![
-                s.clone() * ((prev_running_result * prev_base.clone()) - current_result),
-                s.clone() * ((prev_exp - curr_exp) - Expression::Constant(F::one())),
-                s * (prev_base - curr_base),
+                s.clone() * (bit.clone() * (bit.clone() - Expression::Constant(F::one()))),
+                s.clone() * (next_acc - (cur_acc * Expression::Constant(F::from(2)) + acc_bit)),
+                s.clone() * (next_sq - cur_sq.clone() * cur_sq.clone()),
+                s * (next_prod
+                    - cur_prod
+                        * (Expression::Constant(F::one()) + bit * (cur_sq - Expression::Constant(F::one())))),
             ]
         });
 
+        // the exponent is looked up against the range table whenever `range_selector` is
+        // enabled; when it's disabled the looked-up value collapses to 0, which is always a
+        // member of `0..2^range_bits`.
+        meta.lookup(|meta| {
+            let s = meta.query_selector(range_selector);
+            let exp = meta.query_advice(exponent_column, Rotation::cur());
+            vec![(s * exp, range_table)]
+        });
+
         ExpConfig {
-            advice: [result_column, exponent_column, base_column],
-            selector: sel,
+            advice: [result_column, exponent_column],
+            linear_selector: linear_sel,
+            base_fixed,
+            const_base_selector: const_base_sel,
+            bit,
+            acc_bit,
+            acc,
+            sq,
+            prod,
+            binary_selector: binary_sel,
             instance,
+            constant,
+            range_table,
+            range_selector,
+            range_bits,
+        }
+    }
+
+    /// Populates the fixed range-check table with `0..2^range_bits`. Must be called exactly
+    /// once per circuit synthesis, regardless of whether binary mode is used, since the lookup
+    /// argument references the table unconditionally.
+    fn load_range_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let range_bits = self.config.range_bits;
+        layouter.assign_table(
+            || "exponent range table",
+            |mut table| {
+                for value in 0..(1usize << range_bits) {
+                    table.assign_cell(
+                        || "range value",
+                        self.config.range_table,
+                        value,
+                        || Ok(F::from(value as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Decomposes `value` into its `num_bits` little-endian bits.
+    fn le_bits(value: F, num_bits: usize) -> Vec<F> {
+        let repr = value.to_repr();
+        let bytes = repr.as_ref();
+        (0..num_bits)
+            .map(|i| F::from(((bytes[i / 8] >> (i % 8)) & 1) as u64))
+            .collect()
+    }
+
+    /// Loads the value at instance row `row` as a fresh private cell, so it can be fed into
+    /// `exp` like any other witness.
+    fn load_from_instance(&self, mut layouter: impl Layouter<F>, row: usize) -> Result<Number<F>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || "load from instance",
+            |mut region| {
+                region
+                    .assign_advice_from_instance(
+                        || "instance value",
+                        config.instance,
+                        row,
+                        config.advice[0],
+                        0,
+                    )
+                    .map(Number)
+            },
+        )
+    }
+
+    /// Constrains each `(result, row)` pair to the instance column, for circuits that expose
+    /// more than one result (e.g. batched exponentiation).
+    fn expose_public_many(
+        &self,
+        mut layouter: impl Layouter<F>,
+        results: &[(Number<F>, usize)],
+    ) -> Result<(), Error> {
+        for (num, row) in results {
+            self.expose_public(layouter.namespace(|| format!("expose row {}", row)), num.clone(), *row)?;
         }
+        Ok(())
     }
+}
+
+#[derive(Clone)]
+struct Number<F: FieldExt>(AssignedCell<F, F>);
 
-    fn assign(&self, mut layouter: impl Layouter<F>) -> Result<AssignedCell<F, F>, Error> {
-        // TODO: look into splitting into smaller regions, how is region overlap handled?
+impl<F: FieldExt> ExpInstructions<F> for ExpChip<F> {
+    type Num = Number<F>;
+
+    fn load_private(&self, mut layouter: impl Layouter<F>, value: Option<F>) -> Result<Self::Num, Error> {
+        let config = self.config();
         layouter.assign_region(
-            || "exp_region",
+            || "load private",
             |mut region| {
-                // first row
-                self.config.selector.enable(&mut region, 0)?;
-                // copy the base into the first result column cell
-                let mut result_cell = region.assign_advice_from_instance(
+                region
+                    .assign_advice(|| "private input", config.advice[0], 0, || {
+                        value.ok_or(Error::Synthesis)
+                    })
+                    .map(Number)
+            },
+        )
+    }
+
+    fn load_constant(&self, mut layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || "load constant",
+            |mut region| {
+                region
+                    .assign_advice_from_constant(|| "constant value", config.advice[0], 0, constant)
+                    .map(Number)
+            },
+        )
+    }
+
+    fn exp(
+        &self,
+        layouter: impl Layouter<F>,
+        base: Self::Num,
+        exponent: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        match self.mode {
+            ExpMode::Linear => self.exp_linear(layouter, base, exponent),
+            ExpMode::Binary { num_bits } => self.exp_binary(layouter, base, exponent, num_bits),
+        }
+    }
+
+    fn expose_public(&self, mut layouter: impl Layouter<F>, num: Self::Num, row: usize) -> Result<(), Error> {
+        let config = self.config();
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
+
+/// Identifies one of the linear-mode columns a fault can target: the running result and
+/// exponent (each one row ahead of the step they describe), or the witnessed base (copy-
+/// constrained into the `result` column's own base row — see `exp_linear_inner`).
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LinearColumn {
+    Result,
+    Exponent,
+    Base,
+}
+
+/// A single step to mis-witness in the linear-mode assignment, used to check that the
+/// `exp_linear` gate's two constraints each reject the corresponding kind of bad trace
+/// independently. `row` is the 1-based step index (the first step producing `result_1`/`exp_1`
+/// is `row: 1`), not a physical region row — `exp_linear_inner` spans two physical rows per step.
+#[derive(Clone, Copy)]
+struct LinearFault<F> {
+    row: usize,
+    column: LinearColumn,
+    value: F,
+}
+
+impl<F: FieldExt> ExpChip<F> {
+    fn exp_linear(
+        &self,
+        layouter: impl Layouter<F>,
+        base: Number<F>,
+        exponent: Number<F>,
+    ) -> Result<Number<F>, Error> {
+        self.exp_linear_inner(layouter, base, exponent, None)
+    }
+
+    /// Same as `exp_linear`, but `fault` lets a test witness a wrong value for one step of the
+    /// assignment so we can assert that `verify()` reports the corresponding constraint as
+    /// unsatisfied.
+    #[cfg(test)]
+    fn exp_linear_corrupted(
+        &self,
+        layouter: impl Layouter<F>,
+        base: Number<F>,
+        exponent: Number<F>,
+        fault: LinearFault<F>,
+    ) -> Result<Number<F>, Error> {
+        self.exp_linear_inner(layouter, base, exponent, Some(fault))
+    }
+
+    /// Lays out the witness-base linear recurrence across two advice columns (`result`,
+    /// `exponent`) and two physical rows per step: a "base row" holding a fresh
+    /// copy-constrained copy of the base, immediately followed by the row holding the new
+    /// running result and exponent. The `exp_linear` gate is enabled on the base row and reads
+    /// the running result/exponent one row behind and one row ahead of it, so no column ever
+    /// carries the base down every row the way a naive per-row base copy would.
+    fn exp_linear_inner(
+        &self,
+        mut layouter: impl Layouter<F>,
+        base: Number<F>,
+        exponent: Number<F>,
+        fault: Option<LinearFault<F>>,
+    ) -> Result<Number<F>, Error> {
+        layouter.assign_region(
+            || "exp_linear_region",
+            |mut region| {
+                let cell_fault = |step: usize, column: LinearColumn, value: F| {
+                    fault
+                        .as_ref()
+                        .filter(|f| f.row == step && f.column == column)
+                        .map(|f| f.value)
+                        .unwrap_or(value)
+                };
+
+                // row 0: initial running result/exponent
+                let mut result_cell =
+                    base.0.copy_advice(|| "result_start", &mut region, self.config.advice[0], 0)?;
+                let mut exp_cell =
+                    exponent.0.copy_advice(|| "exp_start", &mut region, self.config.advice[1], 0)?;
+
+                let mut row = 1;
+                let mut step = 1;
+                while let Some(value) = exp_cell.value() {
+                    if value == &F::one() {
+                        break;
+                    }
+
+                    // base row: copy-constrain the base into the gate's rotation window (the row
+                    // directly between the running result/exponent this step reads and the ones
+                    // it produces) instead of carrying a dedicated base column down every row.
+                    match fault
+                        .as_ref()
+                        .filter(|f| f.row == step && f.column == LinearColumn::Base)
+                    {
+                        Some(f) => {
+                            region.assign_advice(|| "base (faulty)", self.config.advice[0], row, || {
+                                Ok(f.value)
+                            })?;
+                        }
+                        None => {
+                            base.0.copy_advice(|| "base", &mut region, self.config.advice[0], row)?;
+                        }
+                    };
+                    self.config.linear_selector.enable(&mut region, row)?;
+
+                    let next_result = cell_fault(
+                        step,
+                        LinearColumn::Result,
+                        *result_cell.value().unwrap() * *base.0.value().unwrap(),
+                    );
+                    let next_exp = cell_fault(
+                        step,
+                        LinearColumn::Exponent,
+                        *exp_cell.value().unwrap() - F::one(),
+                    );
+
+                    result_cell = region.assign_advice(
+                        || "next_result",
+                        self.config.advice[0],
+                        row + 1,
+                        || Ok(next_result),
+                    )?;
+                    exp_cell = region.assign_advice(
+                        || "next_exp",
+                        self.config.advice[1],
+                        row + 1,
+                        || Ok(next_exp),
+                    )?;
+
+                    row += 2;
+                    step += 1;
+                }
+
+                Ok(Number(result_cell))
+            },
+        )
+    }
+
+    /// Linear-mode variant for a base that is a compile-time constant: `base` is read directly
+    /// from the `base_fixed` column in the gate, so no advice column is spent carrying it down
+    /// every row.
+    fn exp_linear_const_base(
+        &self,
+        mut layouter: impl Layouter<F>,
+        base: F,
+        exponent: Number<F>,
+    ) -> Result<Number<F>, Error> {
+        layouter.assign_region(
+            || "exp_linear_const_base_region",
+            |mut region| {
+                self.config.const_base_selector.enable(&mut region, 0)?;
+                region.assign_fixed(|| "base", self.config.base_fixed, 0, || Ok(base))?;
+                let mut result_cell = region.assign_advice_from_constant(
                     || "result_start",
-                    self.config.instance,
-                    0,
                     self.config.advice[0],
                     0,
+                    base,
                 )?;
-                // copy the exponent into the first exponent column cell
-                let mut exp_cell = region.assign_advice_from_instance(
-                    || "exp_start",
-                    self.config.instance,
-                    1,
-                    self.config.advice[1],
-                    0,
-                )?;
-                // copy the base into the first base_column cell
-                let mut base_cell = region.assign_advice_from_instance(
-                    || "base_start",
-                    self.config.instance,
-                    0,
-                    self.config.advice[2],
-                    0,
-                )?;
+                let mut exp_cell =
+                    exponent.0.copy_advice(|| "exp_start", &mut region, self.config.advice[1], 0)?;
 
                 let mut i = 1;
                 while let Some(value) = exp_cell.value() {
@@ -128,14 +562,13 @@ impl<F: FieldExt> ExpChip<F> {
                     }
 
                     if value != &F::from(2) {
-                        self.config.selector.enable(&mut region, i)?;
+                        self.config.const_base_selector.enable(&mut region, i)?;
                     }
+                    region.assign_fixed(|| "base", self.config.base_fixed, i, || Ok(base))?;
 
-                    let next_result = *result_cell.value().unwrap() * *base_cell.value().unwrap();
+                    let next_result = *result_cell.value().unwrap() * base;
                     let next_exp = *exp_cell.value().unwrap() - F::one();
-                    let next_base_cell = *base_cell.value().unwrap() + F::zero();
 
-                    // update the table
                     result_cell = region.assign_advice(
                         || "next_result",
                         self.config.advice[0],
@@ -148,35 +581,102 @@ impl<F: FieldExt> ExpChip<F> {
                         i,
                         || Ok(next_exp),
                     )?;
-                    // TODO: this feels so wrong and wasteful
-                    base_cell = region.assign_advice(
-                        || "next_base",
-                        self.config.advice[2],
-                        i,
-                        || Ok(next_base_cell),
-                    )?;
 
                     i += 1;
                 }
 
-                Ok(result_cell)
+                Ok(Number(result_cell))
             },
         )
     }
 
-    fn expose_public(
+    /// `num_bits` must be `<= self.config.range_bits`, so that the range-check lookup covers
+    /// every value the decomposition below can represent.
+    fn exp_binary(
         &self,
         mut layouter: impl Layouter<F>,
-        cell: &AssignedCell<F, F>,
-        instance_column_row: usize,
-    ) -> Result<(), Error> {
-        layouter.constrain_instance(cell.cell(), self.config.instance, instance_column_row)
+        base: Number<F>,
+        exponent: Number<F>,
+        num_bits: usize,
+    ) -> Result<Number<F>, Error> {
+        let bits = exponent.0.value().map(|v| Self::le_bits(*v, num_bits));
+
+        layouter.assign_region(
+            || "exp_binary_region",
+            |mut region| {
+                // range-check the exponent against the fixed `0..2^range_bits` table before
+                // decomposing it, so the `acc` reconstruction below is a sound, unique
+                // representation rather than one that trusts the public input's range.
+                exponent
+                    .0
+                    .copy_advice(|| "exponent (range check)", &mut region, self.config.advice[1], 0)?;
+                self.config.range_selector.enable(&mut region, 0)?;
+
+                let mut bit_cells = Vec::with_capacity(num_bits);
+                let mut acc_bit_cells = Vec::with_capacity(num_bits);
+
+                let mut acc_cell = region.assign_advice_from_constant(|| "acc_0", self.config.acc, 0, F::zero())?;
+                let mut sq_cell = base.0.copy_advice(|| "sq_0", &mut region, self.config.sq, 0)?;
+                let mut prod_cell =
+                    region.assign_advice_from_constant(|| "prod_0", self.config.prod, 0, F::one())?;
+
+                for i in 0..num_bits {
+                    self.config.binary_selector.enable(&mut region, i)?;
+
+                    let bit_value = bits.as_ref().map(|b| b[i]);
+                    let bit_cell = region.assign_advice(|| "bit", self.config.bit, i, || {
+                        bit_value.ok_or(Error::Synthesis)
+                    })?;
+                    bit_cells.push(bit_cell);
+
+                    let acc_bit_value = bits.as_ref().map(|b| b[num_bits - 1 - i]);
+                    let acc_bit_cell = region.assign_advice(|| "acc_bit", self.config.acc_bit, i, || {
+                        acc_bit_value.ok_or(Error::Synthesis)
+                    })?;
+                    acc_bit_cells.push(acc_bit_cell);
+
+                    let next_acc = acc_cell
+                        .value()
+                        .zip(acc_bit_value.as_ref())
+                        .map(|(acc, b)| F::from(2) * *acc + *b);
+                    let next_sq = sq_cell.value().map(|sq| *sq * *sq);
+                    let next_prod = prod_cell
+                        .value()
+                        .zip(bit_value.as_ref())
+                        .zip(sq_cell.value())
+                        .map(|((prod, b), sq)| *prod * (F::one() + *b * (*sq - F::one())));
+
+                    acc_cell = region.assign_advice(|| "acc_next", self.config.acc, i + 1, || {
+                        next_acc.ok_or(Error::Synthesis)
+                    })?;
+                    sq_cell = region.assign_advice(|| "sq_next", self.config.sq, i + 1, || {
+                        next_sq.ok_or(Error::Synthesis)
+                    })?;
+                    prod_cell = region.assign_advice(|| "prod_next", self.config.prod, i + 1, || {
+                        next_prod.ok_or(Error::Synthesis)
+                    })?;
+                }
+
+                // `acc_bit[i]` is just `bit[num_bits - 1 - i]` viewed from the other end, so the
+                // MSB-first reconstruction in `acc` and the LSB-first square-and-multiply in
+                // `sq`/`prod` can each use a plain next-row rotation over the same underlying bits.
+                for i in 0..num_bits {
+                    region.constrain_equal(acc_bit_cells[i].cell(), bit_cells[num_bits - 1 - i].cell())?;
+                }
+
+                region.constrain_equal(acc_cell.cell(), exponent.0.cell())?;
+
+                Ok(Number(prod_cell))
+            },
+        )
     }
 }
 
 #[derive(Default)]
-struct ExpCircuit<F> {
-    _marker: PhantomData<F>,
+struct ExpCircuit<F: FieldExt> {
+    base: Option<F>,
+    exponent: Option<F>,
+    mode: ExpMode,
 }
 
 impl<F: FieldExt> Circuit<F> for ExpCircuit<F> {
@@ -188,7 +688,182 @@ impl<F: FieldExt> Circuit<F> for ExpCircuit<F> {
     }
 
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
-        ExpChip::configure(meta)
+        ExpChip::configure(meta, EXPONENT_RANGE_BITS)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = ExpChip::construct(config, self.mode);
+        chip.load_range_table(layouter.namespace(|| "range table"))?;
+
+        let base = chip.load_private(layouter.namespace(|| "load base"), self.base)?;
+        let exponent = chip.load_private(layouter.namespace(|| "load exponent"), self.exponent)?;
+        let result = chip.exp(layouter.namespace(|| "exp circuit"), base, exponent)?;
+        chip.expose_public(layouter.namespace(|| "boundary-constraint"), result, 0)?;
+        Ok(())
+    }
+}
+
+/// Proves many `base^exp` pairs in a single circuit, each laid out in its own region but
+/// sharing the chip's `ExpConfig` gate and selector. The instance column is laid out as
+/// `[base_0, exp_0, result_0, base_1, exp_1, result_1, ...]`.
+#[derive(Default)]
+struct BatchExpCircuit<F: FieldExt> {
+    num_pairs: usize,
+    mode: ExpMode,
+    _marker: PhantomData<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for BatchExpCircuit<F> {
+    type Config = ExpConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            num_pairs: self.num_pairs,
+            mode: self.mode,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ExpChip::configure(meta, EXPONENT_RANGE_BITS)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = ExpChip::construct(config, self.mode);
+        chip.load_range_table(layouter.namespace(|| "range table"))?;
+
+        let mut results = Vec::with_capacity(self.num_pairs);
+        for i in 0..self.num_pairs {
+            let instance_row = 3 * i;
+            let base = chip.load_from_instance(layouter.namespace(|| format!("load base {}", i)), instance_row)?;
+            let exponent = chip.load_from_instance(
+                layouter.namespace(|| format!("load exponent {}", i)),
+                instance_row + 1,
+            )?;
+            let result = chip.exp(layouter.namespace(|| format!("exp pair {}", i)), base, exponent)?;
+            results.push((result, instance_row + 2));
+        }
+
+        chip.expose_public_many(layouter.namespace(|| "expose results"), &results)
+    }
+}
+
+/// Proves `base^exp` where `base` is a compile-time constant baked into the circuit (and thus
+/// into the verification key) rather than a witness, using the two-advice-column
+/// `exp_linear_const_base` path.
+#[derive(Default)]
+struct ConstBaseExpCircuit<F: FieldExt> {
+    base: F,
+    exponent: Option<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for ConstBaseExpCircuit<F> {
+    type Config = ExpConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            base: self.base,
+            exponent: None,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ExpChip::configure(meta, EXPONENT_RANGE_BITS)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = ExpChip::construct(config, ExpMode::Linear);
+        chip.load_range_table(layouter.namespace(|| "range table"))?;
+
+        let exponent = chip.load_private(layouter.namespace(|| "load exponent"), self.exponent)?;
+        let result =
+            chip.exp_linear_const_base(layouter.namespace(|| "exp circuit"), self.base, exponent)?;
+        chip.expose_public(layouter.namespace(|| "boundary-constraint"), result, 0)?;
+        Ok(())
+    }
+}
+
+/// Proves `base^exp` where `base` is loaded through `ExpInstructions::load_constant` — an advice
+/// cell pinned to a compile-time constant via `assign_advice_from_constant`/the shared `constant`
+/// fixed column, rather than consuming an instance row. This is the chip-composition path the
+/// instruction trait was introduced for; `ConstBaseExpCircuit` instead pins the base into the
+/// gate itself via `base_fixed` and doesn't go through this method at all.
+#[derive(Default)]
+struct LoadConstantExpCircuit<F: FieldExt> {
+    base: F,
+    exponent: Option<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for LoadConstantExpCircuit<F> {
+    type Config = ExpConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            base: self.base,
+            exponent: None,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ExpChip::configure(meta, EXPONENT_RANGE_BITS)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = ExpChip::construct(config, ExpMode::Linear);
+        chip.load_range_table(layouter.namespace(|| "range table"))?;
+
+        let base = chip.load_constant(layouter.namespace(|| "load constant base"), self.base)?;
+        let exponent = chip.load_private(layouter.namespace(|| "load exponent"), self.exponent)?;
+        let result = chip.exp(layouter.namespace(|| "exp circuit"), base, exponent)?;
+        chip.expose_public(layouter.namespace(|| "boundary-constraint"), result, 0)?;
+        Ok(())
+    }
+}
+
+/// Test-only circuit that runs the linear mode through `exp_linear_corrupted` so a single
+/// cell of the trace can be mis-witnessed and `MockProver::verify` checked for the resulting
+/// constraint failure.
+#[cfg(test)]
+struct FaultyLinearCircuit<F: FieldExt> {
+    base: Option<F>,
+    exponent: Option<F>,
+    fault: LinearFault<F>,
+}
+
+#[cfg(test)]
+impl<F: FieldExt> Circuit<F> for FaultyLinearCircuit<F> {
+    type Config = ExpConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            base: None,
+            exponent: None,
+            fault: self.fault,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ExpChip::configure(meta, EXPONENT_RANGE_BITS)
     }
 
     fn synthesize(
@@ -196,24 +871,193 @@ impl<F: FieldExt> Circuit<F> for ExpCircuit<F> {
         config: Self::Config,
         mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
-        let chip = ExpChip::construct(config);
-        let result = chip.assign(layouter.namespace(|| "exp circuit"))?;
-        chip.expose_public(layouter.namespace(|| "boundary-constraint"), &result, 2)?;
+        let chip = ExpChip::construct(config, ExpMode::Linear);
+        chip.load_range_table(layouter.namespace(|| "range table"))?;
+
+        let base = chip.load_private(layouter.namespace(|| "load base"), self.base)?;
+        let exponent = chip.load_private(layouter.namespace(|| "load exponent"), self.exponent)?;
+        let result = chip.exp_linear_corrupted(
+            layouter.namespace(|| "exp circuit"),
+            base,
+            exponent,
+            self.fault,
+        )?;
+        chip.expose_public(layouter.namespace(|| "boundary-constraint"), result, 0)?;
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ExpCircuit;
-    use halo2_proofs::dev::MockProver;
+    use crate::{
+        BatchExpCircuit, ConstBaseExpCircuit, ExpCircuit, ExpMode, FaultyLinearCircuit, LinearColumn,
+        LinearFault, LoadConstantExpCircuit,
+    };
+    use halo2_proofs::arithmetic::FieldExt;
+    use halo2_proofs::dev::{MockProver, VerifyFailure};
     use halo2_proofs::pasta::Fp;
 
+    fn assert_exp_linear_gate_fails(prover: MockProver<Fp>) {
+        match prover.verify() {
+            Ok(()) => panic!("expected the exp_linear gate to reject a corrupted trace"),
+            Err(failures) => assert!(
+                failures.iter().any(|failure| matches!(
+                    failure,
+                    VerifyFailure::ConstraintNotSatisfied { .. }
+                ) && format!("{:?}", failure).contains("exp_linear")),
+                "expected an exp_linear constraint failure, got: {:?}",
+                failures
+            ),
+        }
+    }
+
+    #[test]
+    fn test_exp_linear_rejects_bad_result_step() {
+        let k = 5;
+        // base^exp at row 1 should be 3*3 = 9; witness 10 instead.
+        let circuit = FaultyLinearCircuit {
+            base: Some(Fp::from(3)),
+            exponent: Some(Fp::from(4)),
+            fault: LinearFault {
+                row: 1,
+                column: LinearColumn::Result,
+                value: Fp::from(10),
+            },
+        };
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(81)]]).unwrap();
+        assert_exp_linear_gate_fails(prover);
+    }
+
+    #[test]
+    fn test_exp_linear_rejects_bad_decrement_step() {
+        let k = 5;
+        // the exponent at row 1 should decrement from 4 to 3; witness 4 (no decrement) instead,
+        // which just delays the natural 4,3,2,1 countdown by one extra step.
+        let circuit = FaultyLinearCircuit {
+            base: Some(Fp::from(3)),
+            exponent: Some(Fp::from(4)),
+            fault: LinearFault {
+                row: 1,
+                column: LinearColumn::Exponent,
+                value: Fp::from(4),
+            },
+        };
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(81)]]).unwrap();
+        assert_exp_linear_gate_fails(prover);
+    }
+
+    #[test]
+    fn test_exp_linear_rejects_bad_base_step() {
+        let k = 5;
+        // base should stay 3 on every row; witness 4 at row 1 instead.
+        let circuit = FaultyLinearCircuit {
+            base: Some(Fp::from(3)),
+            exponent: Some(Fp::from(4)),
+            fault: LinearFault {
+                row: 1,
+                column: LinearColumn::Base,
+                value: Fp::from(4),
+            },
+        };
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(81)]]).unwrap();
+        assert_exp_linear_gate_fails(prover);
+    }
+
+    #[test]
+    fn test_exp_circuit_linear() {
+        let k = 5;
+        let circuit = ExpCircuit {
+            base: Some(Fp::from(3)),
+            exponent: Some(Fp::from(4)),
+            mode: ExpMode::Linear,
+        };
+        let public_inputs = vec![Fp::from(81)];
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn test_exp_circuit_binary() {
+        let k = 5;
+        let circuit = ExpCircuit {
+            base: Some(Fp::from(3)),
+            exponent: Some(Fp::from(4)),
+            mode: ExpMode::Binary { num_bits: 4 },
+        };
+        let public_inputs = vec![Fp::from(81)];
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn test_exp_binary_rejects_out_of_range_exponent() {
+        let k = 5;
+        // `num_bits: 8` can represent values up to 255, but the range table is fixed at
+        // `EXPONENT_RANGE_BITS == 4` (0..16), so an exponent of 20 decomposes cleanly but
+        // should still be rejected by the lookup argument.
+        let circuit = ExpCircuit {
+            base: Some(Fp::from(3)),
+            exponent: Some(Fp::from(20)),
+            mode: ExpMode::Binary { num_bits: 8 },
+        };
+        let public_inputs = vec![Fp::from(3).pow(&[20, 0, 0, 0])];
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        match prover.verify() {
+            Ok(()) => panic!("expected the exponent range check to reject an out-of-range value"),
+            // `ConstraintSystem::lookup` in this halo2_proofs version doesn't take a name, so a
+            // failing lookup argument is only identifiable by its `VerifyFailure::Lookup` variant,
+            // not by a string like the crate's named gates.
+            Err(failures) => assert!(
+                failures
+                    .iter()
+                    .any(|failure| matches!(failure, VerifyFailure::Lookup { .. })),
+                "expected a lookup failure, got: {:?}",
+                failures
+            ),
+        }
+    }
+
+    #[test]
+    fn test_const_base_exp_circuit() {
+        let k = 5;
+        let circuit = ConstBaseExpCircuit {
+            base: Fp::from(3),
+            exponent: Some(Fp::from(4)),
+        };
+        let public_inputs = vec![Fp::from(81)];
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn test_load_constant_exp_circuit() {
+        let k = 5;
+        let circuit = LoadConstantExpCircuit {
+            base: Fp::from(3),
+            exponent: Some(Fp::from(4)),
+        };
+        let public_inputs = vec![Fp::from(81)];
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        prover.assert_satisfied();
+    }
+
     #[test]
-    fn test_exp_circuit() {
-        let k = 4;
-        let public_inputs = vec![Fp::from(3), Fp::from(4), Fp::from(81)];
-        let circuit = ExpCircuit::<Fp>::default();
+    fn test_batch_exp_circuit() {
+        let k = 5;
+        let circuit = BatchExpCircuit {
+            num_pairs: 2,
+            mode: ExpMode::Linear,
+            _marker: Default::default(),
+        };
+        // [base_0, exp_0, result_0, base_1, exp_1, result_1]
+        let public_inputs = vec![
+            Fp::from(3),
+            Fp::from(4),
+            Fp::from(81),
+            Fp::from(2),
+            Fp::from(5),
+            Fp::from(32),
+        ];
         let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
         prover.assert_satisfied();
     }